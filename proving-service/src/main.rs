@@ -6,15 +6,19 @@ use anyhow::{Context, Result as AnyhowResult};
 use zcash_proofs::prover::LocalTxProver;
 use zcash_primitives::{
     sapling::{
-        keys::{ExpandedSpendingKey, OutgoingViewingKey},
+        keys::{ExpandedSpendingKey, OutgoingViewingKey, ProofGenerationKey},
         Diversifier, MerklePath, PaymentAddress, Rseed,
-        redjubjub::PublicKey,
+        redjubjub::{PrivateKey, PublicKey, Signature},
         value::ValueCommitment,
         prover::TxProver,
         Note,
     },
+    transaction::components::Amount,
     constants::SPENDING_KEY_GENERATOR,
 };
+use zcash_proofs::sapling::SaplingVerificationContext;
+use bellman::groth16::{PreparedVerifyingKey, Proof};
+use bls12_381::Bls12;
 // Use types from zcash_primitives dependencies to avoid version conflicts
 
 #[derive(Deserialize)]
@@ -22,23 +26,124 @@ struct SpendProofRequest {
     spending_key: Vec<u8>,  // ask (32 bytes)
     nsk: Vec<u8>,           // nsk (32 bytes) - required
     value: String,
-    rcv: Vec<u8>,
     alpha: Vec<u8>,
     anchor: Vec<u8>,
     merkle_path: Vec<Vec<u8>>,
     position: String,
+    diversifier: Vec<u8>,     // the note's diversifier (11 bytes)
+    rseed: Vec<u8>,           // the note's commitment randomness (32 bytes)
+    rseed_after_zip212: bool, // selects Rseed::AfterZip212 vs Rseed::BeforeZip212
 }
 
 #[derive(Deserialize)]
 struct OutputProofRequest {
     value: String,
-    rcv: Vec<u8>,
     rcm: Vec<u8>,
     diversifier: Vec<u8>,
     pk_d: Vec<u8>,
     esk: Option<Vec<u8>>,
 }
 
+#[derive(Deserialize)]
+struct BundleSpendInput {
+    spending_key: Vec<u8>,
+    nsk: Vec<u8>,
+    value: String,
+    alpha: Vec<u8>,
+    anchor: Vec<u8>,
+    merkle_path: Vec<Vec<u8>>,
+    position: String,
+    diversifier: Vec<u8>,
+    rseed: Vec<u8>,
+    rseed_after_zip212: bool,
+}
+
+#[derive(Deserialize)]
+struct BundleOutputInput {
+    value: String,
+    rcm: Vec<u8>,
+    diversifier: Vec<u8>,
+    pk_d: Vec<u8>,
+    esk: Option<Vec<u8>>,
+}
+
+#[derive(Deserialize)]
+struct SpendSigRequest {
+    ask: Vec<u8>,     // spend authorizing key (32 bytes)
+    alpha: Vec<u8>,   // ar, the same randomizer used to derive rk from ak (32 bytes)
+    sighash: Vec<u8>, // transaction sighash being authorized (32 bytes)
+}
+
+#[derive(Deserialize)]
+struct VerifySpendRequest {
+    proof: Vec<u8>,
+    cv: Vec<u8>,
+    anchor: Vec<u8>,
+    rk: Vec<u8>,
+    nullifier: Vec<u8>,
+    sighash: Vec<u8>,
+    spend_auth_sig: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct VerifyOutputRequest {
+    proof: Vec<u8>,
+    cv: Vec<u8>,
+    cmu: Vec<u8>,
+    epk: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct BundleRequest {
+    spends: Vec<BundleSpendInput>,
+    outputs: Vec<BundleOutputInput>,
+    value_balance: String, // signed, e.g. "-1000" for a net shielding transaction
+    sighash: Vec<u8>,      // 32-byte transaction sighash
+}
+
+/// A spend input for deferred-signing bundles: the spend authorizing key (`ask`) never
+/// reaches this service, so the caller supplies `ak = ask * SPENDING_KEY_GENERATOR` (the
+/// public point baked into the proof) and `nsk` instead of `spending_key`.
+#[derive(Deserialize)]
+struct DeferredSpendInput {
+    ak: Vec<u8>,
+    nsk: Vec<u8>,
+    value: String,
+    alpha: Vec<u8>,
+    anchor: Vec<u8>,
+    merkle_path: Vec<Vec<u8>>,
+    position: String,
+    diversifier: Vec<u8>,
+    rseed: Vec<u8>,
+    rseed_after_zip212: bool,
+}
+
+#[derive(Deserialize)]
+struct DeferredBundleRequest {
+    spends: Vec<DeferredSpendInput>,
+    outputs: Vec<BundleOutputInput>,
+    value_balance: String,
+    sighash: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct FinalizeSpend {
+    proof: Vec<u8>,
+    cv: Vec<u8>,
+    anchor: Vec<u8>,
+    rk: Vec<u8>,
+    nullifier: Vec<u8>,
+    spend_auth_sig: Vec<u8>, // 64-byte signature produced externally under `rsk = ask + alpha` over the sighash
+}
+
+#[derive(Deserialize)]
+struct FinalizeBundleRequest {
+    spends: Vec<FinalizeSpend>,
+    outputs: Vec<OutputDescriptionResponse>,
+    binding_sig: Vec<u8>,
+    sighash: Vec<u8>, // the same sighash returned by /prove/bundle/deferred
+}
+
 #[derive(Serialize)]
 struct ProofResponse {
     proof: Vec<u8>,
@@ -49,8 +154,82 @@ struct ProofResponse {
     cmu: Option<Vec<u8>>,
 }
 
+#[derive(Serialize)]
+struct SpendDescriptionResponse {
+    proof: Vec<u8>,
+    cv: Vec<u8>,
+    rk: Vec<u8>,
+    nullifier: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct OutputDescriptionResponse {
+    proof: Vec<u8>,
+    cv: Vec<u8>,
+    cmu: Vec<u8>,
+    epk: Vec<u8>,
+    // Real note encryption is out of scope for this service today; callers that need a
+    // fully encrypted ciphertext still have to assemble it themselves from these values.
+    enc_ciphertext: Vec<u8>,
+    out_ciphertext: Vec<u8>,
+    // True for padding outputs this service generated to satisfy MIN_SHIELDED_OUTPUTS;
+    // the caller never supplied these and holds no note for them.
+    is_dummy: bool,
+}
+
+#[derive(Serialize)]
+struct SignatureResponse {
+    signature: Vec<u8>, // 64-byte RedJubjub signature (R || S)
+}
+
+#[derive(Serialize)]
+struct VerifyResponse {
+    valid: bool,
+}
+
+#[derive(Serialize)]
+struct BundleResponse {
+    spends: Vec<SpendDescriptionResponse>,
+    outputs: Vec<OutputDescriptionResponse>,
+    binding_sig: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct UnsignedSpendDescription {
+    proof: Vec<u8>,
+    cv: Vec<u8>,
+    rk: Vec<u8>,
+    alpha: Vec<u8>, // echoed back so the external signer can compute rsk = ask + alpha
+    nullifier: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct DeferredBundleResponse {
+    spends: Vec<UnsignedSpendDescription>,
+    outputs: Vec<OutputDescriptionResponse>,
+    binding_sig: Vec<u8>,
+    sighash: Vec<u8>, // the exact bytes each spend_auth_sig must be computed over
+}
+
+#[derive(Serialize)]
+struct FinalizedSpendDescription {
+    proof: Vec<u8>,
+    cv: Vec<u8>,
+    rk: Vec<u8>,
+    spend_auth_sig: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct FinalizedBundleResponse {
+    spends: Vec<FinalizedSpendDescription>,
+    outputs: Vec<OutputDescriptionResponse>,
+    binding_sig: Vec<u8>,
+}
+
 struct AppState {
     prover: Arc<LocalTxProver>,
+    spend_vk: Arc<PreparedVerifyingKey<Bls12>>,
+    output_vk: Arc<PreparedVerifyingKey<Bls12>>,
 }
 
 async fn health() -> Result<HttpResponse> {
@@ -91,62 +270,169 @@ async fn prove_output(
     }
 }
 
-async fn generate_spend_proof_internal(
-    req: SpendProofRequest,
-    prover: &LocalTxProver,
-) -> AnyhowResult<ProofResponse> {
-    // Parse inputs
-    let value = req.value.parse::<u64>()
-        .context("Invalid value")?;
-    let position = req.position.parse::<u64>()
-        .context("Invalid position")?;
-    
-    // Validate input lengths
-    if req.spending_key.len() != 32 {
+async fn sign_spend(
+    req: web::Json<SpendSigRequest>,
+    _state: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    match sign_spend_internal(req.into_inner()) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => {
+            eprintln!("Spend signing error: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": format!("Spend signing failed: {}", e)
+            })))
+        }
+    }
+}
+
+async fn verify_spend(
+    req: web::Json<VerifySpendRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    match verify_spend_internal(req.into_inner(), &state.spend_vk) {
+        Ok(valid) => Ok(HttpResponse::Ok().json(VerifyResponse { valid })),
+        Err(e) => {
+            eprintln!("Spend verification error: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": format!("Spend verification failed: {}", e)
+            })))
+        }
+    }
+}
+
+async fn verify_output(
+    req: web::Json<VerifyOutputRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    match verify_output_internal(req.into_inner(), &state.output_vk) {
+        Ok(valid) => Ok(HttpResponse::Ok().json(VerifyResponse { valid })),
+        Err(e) => {
+            eprintln!("Output verification error: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": format!("Output verification failed: {}", e)
+            })))
+        }
+    }
+}
+
+async fn prove_bundle(
+    req: web::Json<BundleRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    match generate_bundle_internal(req.into_inner(), &state.prover).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => {
+            eprintln!("Bundle assembly error: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": format!("Bundle assembly failed: {}", e)
+            })))
+        }
+    }
+}
+
+async fn prove_bundle_deferred(
+    req: web::Json<DeferredBundleRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    match generate_deferred_bundle_internal(req.into_inner(), &state.prover).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => {
+            eprintln!("Deferred bundle assembly error: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": format!("Deferred bundle assembly failed: {}", e)
+            })))
+        }
+    }
+}
+
+async fn finalize_bundle(
+    req: web::Json<FinalizeBundleRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    match finalize_bundle_internal(req.into_inner(), &state.spend_vk) {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(e) => {
+            eprintln!("Bundle finalization error: {}", e);
+            Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": format!("Bundle finalization failed: {}", e)
+            })))
+        }
+    }
+}
+
+/// Parsed, ready-to-prove spend inputs shared by the single-spend endpoint and the
+/// bundle endpoint so both drive `TxProver::spend_proof` the same way.
+struct ParsedSpend {
+    proof_generation_key: ProofGenerationKey,
+    diversifier: Diversifier,
+    rseed: Rseed,
+    ar: jubjub::Fr,
+    value: u64,
+    anchor: bls12_381::Scalar,
+    merkle_path: MerklePath,
+    position: u64,
+}
+
+fn parse_spend_input(
+    spending_key: &[u8],
+    nsk: &[u8],
+    value: &str,
+    alpha: &[u8],
+    anchor: &[u8],
+    merkle_path: &[Vec<u8>],
+    position: &str,
+    diversifier: &[u8],
+    rseed: &[u8],
+    rseed_after_zip212: bool,
+) -> AnyhowResult<ParsedSpend> {
+    let value = value.parse::<u64>().context("Invalid value")?;
+    let position = position.parse::<u64>().context("Invalid position")?;
+
+    if spending_key.len() != 32 {
         anyhow::bail!("spending_key (ask) must be 32 bytes");
     }
-    if req.nsk.len() != 32 {
+    if nsk.len() != 32 {
         anyhow::bail!("nsk must be 32 bytes");
     }
-    if req.rcv.len() != 32 {
-        anyhow::bail!("rcv must be 32 bytes");
-    }
-    if req.alpha.len() != 32 {
+    if alpha.len() != 32 {
         anyhow::bail!("alpha must be 32 bytes");
     }
-    if req.anchor.len() != 32 {
+    if anchor.len() != 32 {
         anyhow::bail!("anchor must be 32 bytes");
     }
-    
+    if diversifier.len() != 11 {
+        anyhow::bail!("diversifier must be 11 bytes");
+    }
+    if rseed.len() != 32 {
+        anyhow::bail!("rseed must be 32 bytes");
+    }
+
     // Convert ask to Fr (jubjub::Fr from zcash_primitives dependency)
-    use jubjub::Fr;
-    let ask = bytes_to_fr(&req.spending_key)?;
-    
+    let ask = bytes_to_fr(spending_key)?;
+
     // Convert nsk
-    let nsk = bytes_to_fr(&req.nsk)?;
-    
+    let nsk = bytes_to_fr(nsk)?;
+
     // Create ExpandedSpendingKey from ask, nsk, and zero ovk
     // Note: ovk is not needed for proof generation, only for outgoing viewing
     let ovk = OutgoingViewingKey([0u8; 32]);
     let expsk = ExpandedSpendingKey { ask, nsk, ovk };
-    
+
     // Get proof generation key
     let proof_generation_key = expsk.proof_generation_key();
-    
+
     // Convert alpha to jubjub::Fr for ar (randomization)
-    use jubjub::Fr as JubjubFr;
-    let ar = bytes_to_fr(&req.alpha)?;
-    
+    let ar = bytes_to_fr(alpha)?;
+
     // Convert anchor to bls12_381::Scalar
-    use bls12_381::Scalar;
-    let anchor = bytes_to_scalar(&req.anchor)?;
-    
+    let anchor = bytes_to_scalar(anchor)?;
+
     // Build MerklePath from input
     // Each path element is a 32-byte node
     // Node is exported from sapling module (not tree submodule)
     use zcash_primitives::sapling::{Node, note::ExtractedNoteCommitment};
     let mut path_elems = Vec::new();
-    for (i, path_elem) in req.merkle_path.iter().enumerate() {
+    for (i, path_elem) in merkle_path.iter().enumerate() {
         if path_elem.len() != 32 {
             anyhow::bail!("Merkle path element {} must be 32 bytes", i);
         }
@@ -162,26 +448,29 @@ async fn generate_spend_proof_internal(
         };
         path_elems.push(Node::from_cmu(&cmu));
     }
-    
+
     // MerklePath is a type alias for incrementalmerkletree::MerklePath
     // Use Position from incrementalmerkletree 0.4 (matching zcash_primitives version)
     use incrementalmerkletree::Position;
     let pos = Position::from(position);
     let merkle_path = MerklePath::from_parts(path_elems, pos)
         .map_err(|_| anyhow::anyhow!("Failed to create MerklePath - invalid position or path length"))?;
-    
-    // For spend proofs, we need diversifier and rseed
-    // Use default diversifier and generate rseed from rcv
-    let diversifier = Diversifier([0u8; 11]);
-    let rcv_fr = bytes_to_fr(&req.rcv)?;
-    let rseed = Rseed::BeforeZip212(rcv_fr);
-    
-    // Create proving context
-    let mut ctx = prover.new_sapling_proving_context();
-    
-    // Generate the spend proof
-    let (proof_bytes, cv, rk) = prover.spend_proof(
-        &mut ctx,
+
+    // The note actually being spent: its real diversifier and commitment randomness, so the
+    // committed note matches the one whose commitment sits at `position` in the Merkle tree.
+    let mut diversifier_bytes = [0u8; 11];
+    diversifier_bytes.copy_from_slice(diversifier);
+    let diversifier = Diversifier(diversifier_bytes);
+
+    let mut rseed_bytes = [0u8; 32];
+    rseed_bytes.copy_from_slice(rseed);
+    let rseed = if rseed_after_zip212 {
+        Rseed::AfterZip212(rseed_bytes)
+    } else {
+        Rseed::BeforeZip212(bytes_to_fr(&rseed_bytes)?)
+    };
+
+    Ok(ParsedSpend {
         proof_generation_key,
         diversifier,
         rseed,
@@ -189,103 +478,715 @@ async fn generate_spend_proof_internal(
         value,
         anchor,
         merkle_path,
-    )
-    .map_err(|_| anyhow::anyhow!("Proof generation failed - check inputs (anchor, merkle path, etc.)"))?;
-    
-    // Convert rk to bytes
+        position,
+    })
+}
+
+/// Like `parse_spend_input`, but for deferred-signing spends where the authorizing key
+/// never reaches this service: the caller supplies `ak` (the public point `ask * G`
+/// baked into the proof) directly instead of `ask`, since proof generation never needs
+/// `ask` itself - only the RedJubjub spend_auth_sig does, and that is produced externally.
+fn parse_deferred_spend_input(
+    ak: &[u8],
+    nsk: &[u8],
+    value: &str,
+    alpha: &[u8],
+    anchor: &[u8],
+    merkle_path: &[Vec<u8>],
+    position: &str,
+    diversifier: &[u8],
+    rseed: &[u8],
+    rseed_after_zip212: bool,
+) -> AnyhowResult<ParsedSpend> {
+    let value = value.parse::<u64>().context("Invalid value")?;
+    let position = position.parse::<u64>().context("Invalid position")?;
+
+    if ak.len() != 32 {
+        anyhow::bail!("ak must be 32 bytes");
+    }
+    if nsk.len() != 32 {
+        anyhow::bail!("nsk must be 32 bytes");
+    }
+    if alpha.len() != 32 {
+        anyhow::bail!("alpha must be 32 bytes");
+    }
+    if anchor.len() != 32 {
+        anyhow::bail!("anchor must be 32 bytes");
+    }
+    if diversifier.len() != 11 {
+        anyhow::bail!("diversifier must be 11 bytes");
+    }
+    if rseed.len() != 32 {
+        anyhow::bail!("rseed must be 32 bytes");
+    }
+
     use group::GroupEncoding;
-    let rk_bytes = rk.0.to_bytes();
-    
-    Ok(ProofResponse {
-        proof: proof_bytes.to_vec(),
-        cv: cv.to_bytes().to_vec(),
-        rk: Some(rk_bytes.to_vec()),
-        cmu: None,
+    let mut ak_bytes = [0u8; 32];
+    ak_bytes.copy_from_slice(ak);
+    let ak_opt = jubjub::SubgroupPoint::from_bytes(&ak_bytes);
+    let ak = if ak_opt.is_some().into() {
+        ak_opt.unwrap()
+    } else {
+        anyhow::bail!("Invalid ak - not a valid point");
+    };
+
+    let nsk = bytes_to_fr(nsk)?;
+    let proof_generation_key = ProofGenerationKey { ak, nsk };
+
+    let ar = bytes_to_fr(alpha)?;
+    let anchor = bytes_to_scalar(anchor)?;
+
+    use zcash_primitives::sapling::{Node, note::ExtractedNoteCommitment};
+    let mut path_elems = Vec::new();
+    for (i, path_elem) in merkle_path.iter().enumerate() {
+        if path_elem.len() != 32 {
+            anyhow::bail!("Merkle path element {} must be 32 bytes", i);
+        }
+        let mut elem = [0u8; 32];
+        elem.copy_from_slice(path_elem);
+        let cmu_opt = ExtractedNoteCommitment::from_bytes(&elem);
+        let cmu = if cmu_opt.is_some().into() {
+            cmu_opt.unwrap()
+        } else {
+            anyhow::bail!("Invalid merkle path element {} - not a valid commitment", i);
+        };
+        path_elems.push(Node::from_cmu(&cmu));
+    }
+
+    use incrementalmerkletree::Position;
+    let pos = Position::from(position);
+    let merkle_path = MerklePath::from_parts(path_elems, pos)
+        .map_err(|_| anyhow::anyhow!("Failed to create MerklePath - invalid position or path length"))?;
+
+    let mut diversifier_bytes = [0u8; 11];
+    diversifier_bytes.copy_from_slice(diversifier);
+    let diversifier = Diversifier(diversifier_bytes);
+
+    let mut rseed_bytes = [0u8; 32];
+    rseed_bytes.copy_from_slice(rseed);
+    let rseed = if rseed_after_zip212 {
+        Rseed::AfterZip212(rseed_bytes)
+    } else {
+        Rseed::BeforeZip212(bytes_to_fr(&rseed_bytes)?)
+    };
+
+    Ok(ParsedSpend {
+        proof_generation_key,
+        diversifier,
+        rseed,
+        ar,
+        value,
+        anchor,
+        merkle_path,
+        position,
     })
 }
 
-async fn generate_output_proof_internal(
-    req: OutputProofRequest,
+fn drive_spend_proof(
+    ctx: &mut <LocalTxProver as TxProver>::SaplingProvingContext,
     prover: &LocalTxProver,
-) -> AnyhowResult<ProofResponse> {
-    // Parse inputs
-    let value = req.value.parse::<u64>()
-        .context("Invalid value")?;
-    
-    // Validate input lengths
-    if req.rcv.len() != 32 {
-        anyhow::bail!("rcv must be 32 bytes");
-    }
-    if req.rcm.len() != 32 {
+    parsed: ParsedSpend,
+) -> AnyhowResult<([u8; 192], ValueCommitment, PublicKey, [u8; 32])> {
+    // The nullifier isn't a proof output, but every caller needs it to build a real
+    // SpendDescription (or to populate /verify/spend's nullifier), and this is the only
+    // place that still has the note's full plaintext (diversifier, rseed, value) in scope.
+    // Derive it before those fields are consumed by spend_proof below.
+    use zcash_primitives::sapling::value::NoteValue;
+    let viewing_key = parsed.proof_generation_key.to_viewing_key();
+    let payment_address = viewing_key
+        .to_payment_address(parsed.diversifier)
+        .ok_or_else(|| anyhow::anyhow!("diversifier does not correspond to a valid payment address"))?;
+    let note = Note::from_parts(payment_address, NoteValue::from_raw(parsed.value), parsed.rseed.clone());
+    let nullifier = note.nf(&viewing_key.nk, parsed.position);
+
+    let (proof_bytes, cv, rk) = prover
+        .spend_proof(
+            ctx,
+            parsed.proof_generation_key,
+            parsed.diversifier,
+            parsed.rseed,
+            parsed.ar,
+            parsed.value,
+            parsed.anchor,
+            parsed.merkle_path,
+        )
+        .map_err(|_| anyhow::anyhow!("Proof generation failed - check inputs (anchor, merkle path, etc.)"))?;
+
+    Ok((proof_bytes, cv, rk, nullifier.0))
+}
+
+/// Parsed, ready-to-prove output inputs shared by the single-output endpoint and the
+/// bundle endpoint so both drive `TxProver::output_proof` the same way.
+struct ParsedOutput {
+    esk: jubjub::Fr,
+    payment_address: PaymentAddress,
+    rcm: jubjub::Fr,
+    value: u64,
+}
+
+fn parse_output_input(
+    value: &str,
+    rcm: &[u8],
+    diversifier: &[u8],
+    pk_d: &[u8],
+    esk: Option<&[u8]>,
+) -> AnyhowResult<ParsedOutput> {
+    let value = value.parse::<u64>().context("Invalid value")?;
+
+    if rcm.len() != 32 {
         anyhow::bail!("rcm must be 32 bytes");
     }
-    if req.diversifier.len() != 11 {
+    if diversifier.len() != 11 {
         anyhow::bail!("diversifier must be 11 bytes");
     }
-    if req.pk_d.len() != 32 {
+    if pk_d.len() != 32 {
         anyhow::bail!("pk_d must be 32 bytes");
     }
-    
-    // Convert diversifier
-    let mut diversifier_bytes = [0u8; 11];
-    diversifier_bytes.copy_from_slice(&req.diversifier[..]);
-    let diversifier = Diversifier(diversifier_bytes);
-    
+
     // Convert pk_d to PaymentAddress
     // PaymentAddress::from_bytes takes 43 bytes: 11 bytes diversifier + 32 bytes pk_d
     let mut address_bytes = [0u8; 43];
-    address_bytes[..11].copy_from_slice(&req.diversifier[..]);
-    address_bytes[11..].copy_from_slice(&req.pk_d[..]);
-    
+    address_bytes[..11].copy_from_slice(diversifier);
+    address_bytes[11..].copy_from_slice(pk_d);
+
     // Use PaymentAddress::from_bytes which internally uses DiversifiedTransmissionKey::from_bytes
     let payment_address = PaymentAddress::from_bytes(&address_bytes)
         .ok_or_else(|| anyhow::anyhow!("Failed to create payment address from bytes"))?;
-    
+
     // Convert rcm to jubjub::Fr
-    use jubjub::Fr as JubjubFr2;
-    let rcm = bytes_to_fr(&req.rcm)?;
-    
+    let rcm = bytes_to_fr(rcm)?;
+
     // Convert esk (or generate if not provided)
-    let esk = if let Some(esk_bytes) = req.esk {
+    let esk = if let Some(esk_bytes) = esk {
         if esk_bytes.len() != 32 {
             anyhow::bail!("esk must be 32 bytes if provided");
         }
-        bytes_to_fr(&esk_bytes)?
+        bytes_to_fr(esk_bytes)?
     } else {
         // Generate random esk
         use rand_core::OsRng;
         use group::ff::Field;
-        JubjubFr2::random(&mut OsRng)
+        jubjub::Fr::random(&mut OsRng)
     };
-    
-    // Create proving context
-    let mut ctx = prover.new_sapling_proving_context();
-    
-    // Generate the output proof
-    let (proof_bytes, cv) = prover.output_proof(
-        &mut ctx,
+
+    Ok(ParsedOutput {
         esk,
         payment_address,
         rcm,
         value,
+    })
+}
+
+fn drive_output_proof(
+    ctx: &mut <LocalTxProver as TxProver>::SaplingProvingContext,
+    prover: &LocalTxProver,
+    parsed: ParsedOutput,
+) -> (Vec<u8>, ValueCommitment, Vec<u8>, Vec<u8>) {
+    let (proof_bytes, cv) = prover.output_proof(
+        ctx,
+        parsed.esk,
+        parsed.payment_address,
+        parsed.rcm,
+        parsed.value,
     );
-    
-    // Compute note commitment (cmu) for the note
+
+    // Compute note commitment (cmu) for the note. This must use the same trapdoor that was
+    // just fed into output_proof above: Rseed::AfterZip212 treats its argument as a seed and
+    // derives the real trapdoor via PRF^expand, so reusing rcm's raw bytes there would commit
+    // to a different note than the one the circuit actually proved.
     use zcash_primitives::sapling::value::NoteValue;
-    let rcm_bytes: [u8; 32] = {
-        let mut arr = [0u8; 32];
-        let fr_bytes = rcm.to_bytes();
-        arr.copy_from_slice(&fr_bytes[..32]);
-        arr
-    };
-    let note = Note::from_parts(payment_address, NoteValue::from_raw(value), Rseed::AfterZip212(rcm_bytes));
+    let note = Note::from_parts(
+        parsed.payment_address,
+        NoteValue::from_raw(parsed.value),
+        Rseed::BeforeZip212(parsed.rcm),
+    );
     let cmu = note.cmu();
-    
+
+    // epk = esk * g_d, the ephemeral public key the recipient uses to derive the shared secret
+    use group::GroupEncoding;
+    let epk = parsed.payment_address.g_d().expect("valid payment address has a g_d") * parsed.esk;
+
+    (proof_bytes.to_vec(), cv, epk.to_bytes().to_vec(), cmu.to_bytes().to_vec())
+}
+
+/// Any bundle containing a shielded spend must contain at least this many shielded
+/// outputs, matching the MIN_SHIELDED_OUTPUTS padding rule from the sapling-crypto
+/// bundle builder, so a single-recipient spend doesn't produce a trivially analyzable
+/// one-output transaction.
+const MIN_SHIELDED_OUTPUTS: usize = 2;
+
+/// Builds a zero-value output to a freshly sampled, unlinkable payment address, whose
+/// value commitment balances into the bundle like any other output.
+fn dummy_output_input() -> AnyhowResult<ParsedOutput> {
+    use group::ff::Field;
+    use rand_core::{OsRng, RngCore};
+
+    let mut rng = OsRng;
+    let payment_address = loop {
+        let mut diversifier_bytes = [0u8; 11];
+        rng.fill_bytes(&mut diversifier_bytes);
+        let diversifier = Diversifier(diversifier_bytes);
+        let g_d = match diversifier.g_d() {
+            Some(g_d) => g_d,
+            None => continue,
+        };
+        // No real incoming viewing key exists for a dummy note, so pk_d is just some
+        // random point in the prime-order subgroup reachable from g_d.
+        let fake_ivk = jubjub::Fr::random(&mut rng);
+        let pk_d = g_d * fake_ivk;
+        if let Some(address) = PaymentAddress::from_parts(diversifier, pk_d) {
+            break address;
+        }
+    };
+
+    Ok(ParsedOutput {
+        esk: jubjub::Fr::random(&mut rng),
+        payment_address,
+        rcm: jubjub::Fr::random(&mut rng),
+        value: 0,
+    })
+}
+
+/// Pads `outputs` with dummy outputs (proved through the same context so their value
+/// commitments participate in the binding signature) until the bundle meets
+/// `MIN_SHIELDED_OUTPUTS`, but only when the bundle actually spends a shielded note -
+/// a pure-output bundle has no privacy reason to pad.
+fn pad_dummy_outputs(
+    ctx: &mut <LocalTxProver as TxProver>::SaplingProvingContext,
+    prover: &LocalTxProver,
+    outputs: &mut Vec<OutputDescriptionResponse>,
+    has_spends: bool,
+) -> AnyhowResult<()> {
+    if !has_spends {
+        return Ok(());
+    }
+    use group::GroupEncoding;
+    while outputs.len() < MIN_SHIELDED_OUTPUTS {
+        let parsed = dummy_output_input()?;
+        let (proof_bytes, cv, epk, cmu) = drive_output_proof(ctx, prover, parsed);
+        outputs.push(OutputDescriptionResponse {
+            proof: proof_bytes,
+            cv: cv.to_bytes().to_vec(),
+            cmu,
+            epk,
+            enc_ciphertext: Vec::new(),
+            out_ciphertext: Vec::new(),
+            is_dummy: true,
+        });
+    }
+    Ok(())
+}
+
+async fn generate_spend_proof_internal(
+    req: SpendProofRequest,
+    prover: &LocalTxProver,
+) -> AnyhowResult<ProofResponse> {
+    let parsed = parse_spend_input(
+        &req.spending_key,
+        &req.nsk,
+        &req.value,
+        &req.alpha,
+        &req.anchor,
+        &req.merkle_path,
+        &req.position,
+        &req.diversifier,
+        &req.rseed,
+        req.rseed_after_zip212,
+    )?;
+
+    // Create proving context
+    let mut ctx = prover.new_sapling_proving_context();
+
+    let (proof_bytes, cv, rk, _nullifier) = drive_spend_proof(&mut ctx, prover, parsed)?;
+
+    // Convert rk to bytes
+    use group::GroupEncoding;
+    let rk_bytes = rk.0.to_bytes();
+
     Ok(ProofResponse {
         proof: proof_bytes.to_vec(),
         cv: cv.to_bytes().to_vec(),
+        rk: Some(rk_bytes.to_vec()),
+        cmu: None,
+    })
+}
+
+async fn generate_output_proof_internal(
+    req: OutputProofRequest,
+    prover: &LocalTxProver,
+) -> AnyhowResult<ProofResponse> {
+    let parsed = parse_output_input(
+        &req.value,
+        &req.rcm,
+        &req.diversifier,
+        &req.pk_d,
+        req.esk.as_deref(),
+    )?;
+
+    // Create proving context
+    let mut ctx = prover.new_sapling_proving_context();
+
+    let (proof_bytes, cv, _epk, cmu) = drive_output_proof(&mut ctx, prover, parsed);
+
+    Ok(ProofResponse {
+        proof: proof_bytes,
+        cv: cv.to_bytes().to_vec(),
         rk: None,
-        cmu: Some(cmu.to_bytes().to_vec()),
+        cmu: Some(cmu),
+    })
+}
+
+fn sign_spend_internal(req: SpendSigRequest) -> AnyhowResult<SignatureResponse> {
+    if req.ask.len() != 32 {
+        anyhow::bail!("ask must be 32 bytes");
+    }
+    if req.alpha.len() != 32 {
+        anyhow::bail!("alpha must be 32 bytes");
+    }
+    if req.sighash.len() != 32 {
+        anyhow::bail!("sighash must be 32 bytes");
+    }
+
+    let ask = bytes_to_fr(&req.ask)?;
+    let alpha = bytes_to_fr(&req.alpha)?;
+
+    // rsk = ask + alpha, the randomized signing key matching the rk (alpha-randomized ak)
+    // returned alongside the spend proof
+    let rsk = PrivateKey(ask).randomize(alpha);
+
+    use rand_core::OsRng;
+    let signature = rsk.sign(&req.sighash, &mut OsRng, SPENDING_KEY_GENERATOR);
+
+    let mut signature_bytes = Vec::with_capacity(64);
+    signature.write(&mut signature_bytes)?;
+
+    Ok(SignatureResponse {
+        signature: signature_bytes,
+    })
+}
+
+fn verify_spend_internal(
+    req: VerifySpendRequest,
+    spend_vk: &PreparedVerifyingKey<Bls12>,
+) -> AnyhowResult<bool> {
+    if req.cv.len() != 32 {
+        anyhow::bail!("cv must be 32 bytes");
+    }
+    if req.anchor.len() != 32 {
+        anyhow::bail!("anchor must be 32 bytes");
+    }
+    if req.rk.len() != 32 {
+        anyhow::bail!("rk must be 32 bytes");
+    }
+    if req.nullifier.len() != 32 {
+        anyhow::bail!("nullifier must be 32 bytes");
+    }
+    if req.sighash.len() != 32 {
+        anyhow::bail!("sighash must be 32 bytes");
+    }
+    if req.spend_auth_sig.len() != 64 {
+        anyhow::bail!("spend_auth_sig must be 64 bytes");
+    }
+
+    use group::GroupEncoding;
+    let mut cv_bytes = [0u8; 32];
+    cv_bytes.copy_from_slice(&req.cv);
+    let cv_opt = jubjub::ExtendedPoint::from_bytes(&cv_bytes);
+    let cv = if cv_opt.is_some().into() {
+        cv_opt.unwrap()
+    } else {
+        anyhow::bail!("Invalid cv");
+    };
+
+    let anchor = bytes_to_scalar(&req.anchor)?;
+
+    let mut nullifier = [0u8; 32];
+    nullifier.copy_from_slice(&req.nullifier);
+
+    let rk = PublicKey::read(&req.rk[..]).context("Invalid rk")?;
+
+    let mut sighash = [0u8; 32];
+    sighash.copy_from_slice(&req.sighash);
+
+    let spend_auth_sig = Signature::read(&req.spend_auth_sig[..]).context("Invalid spend_auth_sig")?;
+
+    let zkproof = Proof::<Bls12>::read(&req.proof[..]).context("Invalid proof")?;
+
+    let mut ctx = SaplingVerificationContext::new();
+    Ok(ctx.check_spend(
+        cv,
+        anchor,
+        &nullifier,
+        rk,
+        &sighash,
+        spend_auth_sig,
+        zkproof,
+        spend_vk,
+    ))
+}
+
+fn verify_output_internal(
+    req: VerifyOutputRequest,
+    output_vk: &PreparedVerifyingKey<Bls12>,
+) -> AnyhowResult<bool> {
+    if req.cv.len() != 32 {
+        anyhow::bail!("cv must be 32 bytes");
+    }
+    if req.cmu.len() != 32 {
+        anyhow::bail!("cmu must be 32 bytes");
+    }
+    if req.epk.len() != 32 {
+        anyhow::bail!("epk must be 32 bytes");
+    }
+
+    use group::GroupEncoding;
+    let mut cv_bytes = [0u8; 32];
+    cv_bytes.copy_from_slice(&req.cv);
+    let cv_opt = jubjub::ExtendedPoint::from_bytes(&cv_bytes);
+    let cv = if cv_opt.is_some().into() {
+        cv_opt.unwrap()
+    } else {
+        anyhow::bail!("Invalid cv");
+    };
+
+    let cmu = bytes_to_scalar(&req.cmu)?;
+
+    let mut epk_bytes = [0u8; 32];
+    epk_bytes.copy_from_slice(&req.epk);
+    let epk_opt = jubjub::ExtendedPoint::from_bytes(&epk_bytes);
+    let epk = if epk_opt.is_some().into() {
+        epk_opt.unwrap()
+    } else {
+        anyhow::bail!("Invalid epk");
+    };
+
+    let zkproof = Proof::<Bls12>::read(&req.proof[..]).context("Invalid proof")?;
+
+    let mut ctx = SaplingVerificationContext::new();
+    Ok(ctx.check_output(cv, cmu, epk, zkproof, output_vk))
+}
+
+async fn generate_bundle_internal(
+    req: BundleRequest,
+    prover: &LocalTxProver,
+) -> AnyhowResult<BundleResponse> {
+    if req.sighash.len() != 32 {
+        anyhow::bail!("sighash must be 32 bytes");
+    }
+    let mut sighash = [0u8; 32];
+    sighash.copy_from_slice(&req.sighash);
+
+    let value_balance_raw = req.value_balance.parse::<i64>()
+        .context("Invalid value_balance")?;
+    let value_balance = Amount::from_i64(value_balance_raw)
+        .map_err(|_| anyhow::anyhow!("value_balance out of range"))?;
+
+    // A single shared proving context is used for every spend_proof/output_proof call below:
+    // spend_proof/output_proof sample their own value-commitment trapdoors internally and
+    // accumulate them in ctx, which is what actually lets binding_sig below balance them.
+    let mut ctx = prover.new_sapling_proving_context();
+
+    let mut spends = Vec::with_capacity(req.spends.len());
+    for spend in req.spends {
+        let parsed = parse_spend_input(
+            &spend.spending_key,
+            &spend.nsk,
+            &spend.value,
+            &spend.alpha,
+            &spend.anchor,
+            &spend.merkle_path,
+            &spend.position,
+            &spend.diversifier,
+            &spend.rseed,
+            spend.rseed_after_zip212,
+        )?;
+        let (proof_bytes, cv, rk, nullifier) = drive_spend_proof(&mut ctx, prover, parsed)?;
+
+        use group::GroupEncoding;
+        spends.push(SpendDescriptionResponse {
+            proof: proof_bytes.to_vec(),
+            cv: cv.to_bytes().to_vec(),
+            rk: rk.0.to_bytes().to_vec(),
+            nullifier: nullifier.to_vec(),
+        });
+    }
+
+    let mut outputs = Vec::with_capacity(req.outputs.len());
+    for output in req.outputs {
+        let parsed = parse_output_input(
+            &output.value,
+            &output.rcm,
+            &output.diversifier,
+            &output.pk_d,
+            output.esk.as_deref(),
+        )?;
+        let (proof_bytes, cv, epk, cmu) = drive_output_proof(&mut ctx, prover, parsed);
+
+        outputs.push(OutputDescriptionResponse {
+            proof: proof_bytes,
+            cv: cv.to_bytes().to_vec(),
+            cmu,
+            epk,
+            // Note encryption is not implemented yet; these are zero-length placeholders
+            // until a client-side or service-side encryptor fills them in.
+            enc_ciphertext: Vec::new(),
+            out_ciphertext: Vec::new(),
+            is_dummy: false,
+        });
+    }
+
+    pad_dummy_outputs(&mut ctx, prover, &mut outputs, !spends.is_empty())?;
+
+    let binding_sig = prover
+        .binding_sig(&mut ctx, value_balance, &sighash)
+        .map_err(|_| anyhow::anyhow!("Binding signature failed - value commitments do not balance"))?;
+
+    use group::GroupEncoding;
+    let mut binding_sig_bytes = Vec::with_capacity(64);
+    binding_sig_bytes.extend_from_slice(&binding_sig.0.to_bytes());
+    binding_sig_bytes.extend_from_slice(&binding_sig.1.to_bytes());
+
+    Ok(BundleResponse {
+        spends,
+        outputs,
+        binding_sig: binding_sig_bytes,
+    })
+}
+
+async fn generate_deferred_bundle_internal(
+    req: DeferredBundleRequest,
+    prover: &LocalTxProver,
+) -> AnyhowResult<DeferredBundleResponse> {
+    if req.sighash.len() != 32 {
+        anyhow::bail!("sighash must be 32 bytes");
+    }
+    let mut sighash = [0u8; 32];
+    sighash.copy_from_slice(&req.sighash);
+
+    let value_balance_raw = req.value_balance.parse::<i64>()
+        .context("Invalid value_balance")?;
+    let value_balance = Amount::from_i64(value_balance_raw)
+        .map_err(|_| anyhow::anyhow!("value_balance out of range"))?;
+
+    // Same shared context as /prove/bundle: the binding signature only needs the value
+    // commitment trapdoors that spend_proof/output_proof sample internally and accumulate
+    // in ctx - never `ask` - so it can be produced here even though every spend_auth_sig
+    // is still missing.
+    let mut ctx = prover.new_sapling_proving_context();
+
+    let mut spends = Vec::with_capacity(req.spends.len());
+    for spend in req.spends {
+        let parsed = parse_deferred_spend_input(
+            &spend.ak,
+            &spend.nsk,
+            &spend.value,
+            &spend.alpha,
+            &spend.anchor,
+            &spend.merkle_path,
+            &spend.position,
+            &spend.diversifier,
+            &spend.rseed,
+            spend.rseed_after_zip212,
+        )?;
+        let alpha = spend.alpha.clone();
+        let (proof_bytes, cv, rk, nullifier) = drive_spend_proof(&mut ctx, prover, parsed)?;
+
+        use group::GroupEncoding;
+        spends.push(UnsignedSpendDescription {
+            proof: proof_bytes.to_vec(),
+            cv: cv.to_bytes().to_vec(),
+            rk: rk.0.to_bytes().to_vec(),
+            alpha,
+            nullifier: nullifier.to_vec(),
+        });
+    }
+
+    let mut outputs = Vec::with_capacity(req.outputs.len());
+    for output in req.outputs {
+        let parsed = parse_output_input(
+            &output.value,
+            &output.rcm,
+            &output.diversifier,
+            &output.pk_d,
+            output.esk.as_deref(),
+        )?;
+        let (proof_bytes, cv, epk, cmu) = drive_output_proof(&mut ctx, prover, parsed);
+
+        outputs.push(OutputDescriptionResponse {
+            proof: proof_bytes,
+            cv: cv.to_bytes().to_vec(),
+            cmu,
+            epk,
+            enc_ciphertext: Vec::new(),
+            out_ciphertext: Vec::new(),
+            is_dummy: false,
+        });
+    }
+
+    pad_dummy_outputs(&mut ctx, prover, &mut outputs, !spends.is_empty())?;
+
+    let binding_sig = prover
+        .binding_sig(&mut ctx, value_balance, &sighash)
+        .map_err(|_| anyhow::anyhow!("Binding signature failed - value commitments do not balance"))?;
+
+    use group::GroupEncoding;
+    let mut binding_sig_bytes = Vec::with_capacity(64);
+    binding_sig_bytes.extend_from_slice(&binding_sig.0.to_bytes());
+    binding_sig_bytes.extend_from_slice(&binding_sig.1.to_bytes());
+
+    Ok(DeferredBundleResponse {
+        spends,
+        outputs,
+        binding_sig: binding_sig_bytes,
+        sighash: sighash.to_vec(),
+    })
+}
+
+fn finalize_bundle_internal(
+    req: FinalizeBundleRequest,
+    spend_vk: &PreparedVerifyingKey<Bls12>,
+) -> AnyhowResult<FinalizedBundleResponse> {
+    if req.sighash.len() != 32 {
+        anyhow::bail!("sighash must be 32 bytes");
+    }
+
+    let mut spends = Vec::with_capacity(req.spends.len());
+    for spend in req.spends {
+        if spend.spend_auth_sig.len() != 64 {
+            anyhow::bail!("spend_auth_sig must be 64 bytes");
+        }
+
+        // Verify the externally-produced spend_auth_sig against the proof/rk this service
+        // generated earlier, the same way /verify/spend does, before the bundle is declared
+        // finalized - otherwise a garbage or mismatched signature would splice in silently.
+        let valid = verify_spend_internal(
+            VerifySpendRequest {
+                proof: spend.proof.clone(),
+                cv: spend.cv.clone(),
+                anchor: spend.anchor.clone(),
+                rk: spend.rk.clone(),
+                nullifier: spend.nullifier.clone(),
+                sighash: req.sighash.clone(),
+                spend_auth_sig: spend.spend_auth_sig.clone(),
+            },
+            spend_vk,
+        )?;
+        if !valid {
+            anyhow::bail!("spend_auth_sig does not verify against rk and sighash");
+        }
+
+        spends.push(FinalizedSpendDescription {
+            proof: spend.proof,
+            cv: spend.cv,
+            rk: spend.rk,
+            spend_auth_sig: spend.spend_auth_sig,
+        });
+    }
+
+    Ok(FinalizedBundleResponse {
+        spends,
+        outputs: req.outputs,
+        binding_sig: req.binding_sig,
     })
 }
 
@@ -359,12 +1260,12 @@ async fn main() -> std::io::Result<()> {
             // Default fallback
             "../miden-browser-wallet/public/params/sapling-output.params".to_string()
         });
-    
+
     // Initialize the prover
     println!("Loading Sapling parameters...");
     println!("  Spend params: {}", spend_params);
     println!("  Output params: {}", output_params);
-    
+
     // Check if parameter files exist
     if !std::path::Path::new(&spend_params).exists() {
         eprintln!("ERROR: Sapling spend params not found at: {}", spend_params);
@@ -376,19 +1277,32 @@ async fn main() -> std::io::Result<()> {
         eprintln!("Please download from: https://download.z.cash/downloads/sapling-output.params");
         std::process::exit(1);
     }
-    
-    let prover = LocalTxProver::new(
+
+    // Parse the (multi-hundred-megabyte) parameter files exactly once and build both the
+    // prover and the prepared verifying keys from that single pass, instead of reading the
+    // files a second time just for the verifying keys.
+    println!("Loading Sapling parameters and verifying keys...");
+    let (spend_params_parsed, spend_vk, output_params_parsed, output_vk, _) = zcash_proofs::load_parameters(
         std::path::Path::new(&spend_params),
         std::path::Path::new(&output_params),
+        None,
     );
-    
+    let prover = LocalTxProver::new_from_parts(
+        spend_params_parsed,
+        spend_vk.clone(),
+        output_params_parsed,
+        output_vk.clone(),
+    );
+
     let app_state = web::Data::new(AppState {
         prover: Arc::new(prover),
+        spend_vk: Arc::new(spend_vk),
+        output_vk: Arc::new(output_vk),
     });
-    
+
     println!("Starting Zcash Proving Service on http://localhost:8081");
     println!("Ready to generate Sapling proofs");
-    
+
     HttpServer::new(move || {
         let cors = Cors::default()
             .allow_any_origin()
@@ -402,8 +1316,260 @@ async fn main() -> std::io::Result<()> {
             .route("/health", web::get().to(health))
             .route("/prove/spend", web::post().to(prove_spend))
             .route("/prove/output", web::post().to(prove_output))
+            .route("/sign/spend", web::post().to(sign_spend))
+            .route("/verify/spend", web::post().to(verify_spend))
+            .route("/verify/output", web::post().to(verify_output))
+            .route("/prove/bundle", web::post().to(prove_bundle))
+            .route("/prove/bundle/deferred", web::post().to(prove_bundle_deferred))
+            .route("/prove/bundle/finalize", web::post().to(finalize_bundle))
     })
     .bind("127.0.0.1:8081")?
     .run()
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The Sapling Groth16 parameters are multi-hundred-megabyte downloads, so these
+    /// round-trip tests only run when they're available locally (same env vars `main`
+    /// reads); otherwise they skip instead of failing CI.
+    fn test_prover() -> Option<(LocalTxProver, PreparedVerifyingKey<Bls12>, PreparedVerifyingKey<Bls12>)> {
+        let spend_params = std::env::var("SAPLING_SPEND_PARAMS").ok()?;
+        let output_params = std::env::var("SAPLING_OUTPUT_PARAMS").ok()?;
+        if !std::path::Path::new(&spend_params).exists() || !std::path::Path::new(&output_params).exists() {
+            return None;
+        }
+
+        let (spend_params_parsed, spend_vk, output_params_parsed, output_vk, _) = zcash_proofs::load_parameters(
+            std::path::Path::new(&spend_params),
+            std::path::Path::new(&output_params),
+            None,
+        );
+        let prover = LocalTxProver::new_from_parts(
+            spend_params_parsed,
+            spend_vk.clone(),
+            output_params_parsed,
+            output_vk.clone(),
+        );
+        Some((prover, spend_vk, output_vk))
+    }
+
+    #[test]
+    fn output_proof_round_trips_through_verify() {
+        let (prover, _spend_vk, output_vk) = match test_prover() {
+            Some(p) => p,
+            None => {
+                eprintln!("skipping: SAPLING_SPEND_PARAMS/SAPLING_OUTPUT_PARAMS not set");
+                return;
+            }
+        };
+
+        let parsed = dummy_output_input().expect("dummy output input");
+        let mut ctx = prover.new_sapling_proving_context();
+        let (proof_bytes, cv, epk, cmu) = drive_output_proof(&mut ctx, &prover, parsed);
+
+        use group::GroupEncoding;
+        let valid = verify_output_internal(
+            VerifyOutputRequest {
+                proof: proof_bytes,
+                cv: cv.to_bytes().to_vec(),
+                cmu,
+                epk,
+            },
+            &output_vk,
+        )
+        .expect("verify_output_internal should not error on a freshly generated proof");
+
+        assert!(valid, "a freshly generated output proof must verify");
+    }
+
+    #[test]
+    fn spend_proof_round_trips_through_verify() {
+        let (prover, spend_vk, _output_vk) = match test_prover() {
+            Some(p) => p,
+            None => {
+                eprintln!("skipping: SAPLING_SPEND_PARAMS/SAPLING_OUTPUT_PARAMS not set");
+                return;
+            }
+        };
+
+        use group::{ff::Field, GroupEncoding};
+        use rand_core::OsRng;
+        use zcash_primitives::merkle_tree::{CommitmentTree, IncrementalWitness};
+        use zcash_primitives::sapling::{value::NoteValue, Node};
+
+        let mut rng = OsRng;
+
+        let ask = jubjub::Fr::random(&mut rng);
+        let nsk = jubjub::Fr::random(&mut rng);
+        let expsk = ExpandedSpendingKey {
+            ask,
+            nsk,
+            ovk: OutgoingViewingKey([0u8; 32]),
+        };
+        let proof_generation_key = expsk.proof_generation_key();
+        let viewing_key = proof_generation_key.to_viewing_key();
+
+        let diversifier = Diversifier([0u8; 11]);
+        let payment_address = viewing_key
+            .to_payment_address(diversifier)
+            .expect("diversifier has a valid g_d");
+
+        let value = 1000u64;
+        let rseed_bytes: [u8; 32] = jubjub::Fr::random(&mut rng).to_bytes();
+        let note = Note::from_parts(
+            payment_address,
+            NoteValue::from_raw(value),
+            Rseed::BeforeZip212(bytes_to_fr(&rseed_bytes).unwrap()),
+        );
+        let cmu = note.cmu();
+
+        // Build a one-leaf commitment tree so the note has a real anchor/merkle_path.
+        let mut tree = CommitmentTree::<Node>::empty();
+        tree.append(Node::from_cmu(&cmu)).expect("single leaf always fits");
+        let witness = IncrementalWitness::from_tree(tree);
+        let merkle_path = witness.path().expect("path to the only leaf");
+        let anchor = witness.root().into();
+
+        let alpha = jubjub::Fr::random(&mut rng);
+        let mut ctx = prover.new_sapling_proving_context();
+        let (proof_bytes, cv, rk) = prover
+            .spend_proof(
+                &mut ctx,
+                proof_generation_key,
+                diversifier,
+                Rseed::BeforeZip212(bytes_to_fr(&rseed_bytes).unwrap()),
+                alpha,
+                value,
+                anchor,
+                merkle_path,
+            )
+            .expect("spend proof generation");
+
+        let nullifier = note.nf(&viewing_key.nk, 0);
+
+        let sighash = [7u8; 32];
+        let rsk = PrivateKey(ask).randomize(alpha);
+        let spend_auth_sig = rsk.sign(&sighash, &mut rng, SPENDING_KEY_GENERATOR);
+        let mut spend_auth_sig_bytes = Vec::with_capacity(64);
+        spend_auth_sig
+            .write(&mut spend_auth_sig_bytes)
+            .expect("signature serializes");
+
+        let valid = verify_spend_internal(
+            VerifySpendRequest {
+                proof: proof_bytes.to_vec(),
+                cv: cv.to_bytes().to_vec(),
+                anchor: anchor.to_bytes().to_vec(),
+                rk: rk.0.to_bytes().to_vec(),
+                nullifier: nullifier.0.to_vec(),
+                sighash: sighash.to_vec(),
+                spend_auth_sig: spend_auth_sig_bytes,
+            },
+            &spend_vk,
+        )
+        .expect("verify_spend_internal should not error on a freshly generated proof");
+
+        assert!(valid, "a freshly generated spend proof must verify");
+    }
+
+    #[test]
+    fn finalize_bundle_rejects_tampered_spend_auth_sig() {
+        let (prover, spend_vk, _output_vk) = match test_prover() {
+            Some(p) => p,
+            None => {
+                eprintln!("skipping: SAPLING_SPEND_PARAMS/SAPLING_OUTPUT_PARAMS not set");
+                return;
+            }
+        };
+
+        use group::{ff::Field, GroupEncoding};
+        use rand_core::OsRng;
+        use zcash_primitives::merkle_tree::{CommitmentTree, IncrementalWitness};
+        use zcash_primitives::sapling::{value::NoteValue, Node};
+
+        let mut rng = OsRng;
+
+        let ask = jubjub::Fr::random(&mut rng);
+        let nsk = jubjub::Fr::random(&mut rng);
+        let expsk = ExpandedSpendingKey {
+            ask,
+            nsk,
+            ovk: OutgoingViewingKey([0u8; 32]),
+        };
+        let proof_generation_key = expsk.proof_generation_key();
+        let viewing_key = proof_generation_key.to_viewing_key();
+
+        let diversifier = Diversifier([0u8; 11]);
+        let payment_address = viewing_key
+            .to_payment_address(diversifier)
+            .expect("diversifier has a valid g_d");
+
+        let value = 1000u64;
+        let rseed_bytes: [u8; 32] = jubjub::Fr::random(&mut rng).to_bytes();
+        let note = Note::from_parts(
+            payment_address,
+            NoteValue::from_raw(value),
+            Rseed::BeforeZip212(bytes_to_fr(&rseed_bytes).unwrap()),
+        );
+        let cmu = note.cmu();
+
+        let mut tree = CommitmentTree::<Node>::empty();
+        tree.append(Node::from_cmu(&cmu)).expect("single leaf always fits");
+        let witness = IncrementalWitness::from_tree(tree);
+        let merkle_path = witness.path().expect("path to the only leaf");
+        let anchor = witness.root().into();
+
+        let alpha = jubjub::Fr::random(&mut rng);
+        let mut ctx = prover.new_sapling_proving_context();
+        let (proof_bytes, cv, rk) = prover
+            .spend_proof(
+                &mut ctx,
+                proof_generation_key,
+                diversifier,
+                Rseed::BeforeZip212(bytes_to_fr(&rseed_bytes).unwrap()),
+                alpha,
+                value,
+                anchor,
+                merkle_path,
+            )
+            .expect("spend proof generation");
+
+        let nullifier = note.nf(&viewing_key.nk, 0);
+
+        let sighash = [7u8; 32];
+        let rsk = PrivateKey(ask).randomize(alpha);
+        let spend_auth_sig = rsk.sign(&sighash, &mut rng, SPENDING_KEY_GENERATOR);
+        let mut spend_auth_sig_bytes = Vec::with_capacity(64);
+        spend_auth_sig
+            .write(&mut spend_auth_sig_bytes)
+            .expect("signature serializes");
+
+        // Flip a byte so the signature no longer validates against rk/sighash.
+        spend_auth_sig_bytes[0] ^= 0xff;
+
+        let result = finalize_bundle_internal(
+            FinalizeBundleRequest {
+                spends: vec![FinalizeSpend {
+                    proof: proof_bytes.to_vec(),
+                    cv: cv.to_bytes().to_vec(),
+                    anchor: anchor.to_bytes().to_vec(),
+                    rk: rk.0.to_bytes().to_vec(),
+                    nullifier: nullifier.0.to_vec(),
+                    spend_auth_sig: spend_auth_sig_bytes,
+                }],
+                outputs: Vec::new(),
+                binding_sig: vec![0u8; 64],
+                sighash: sighash.to_vec(),
+            },
+            &spend_vk,
+        );
+
+        assert!(
+            result.is_err(),
+            "finalize_bundle_internal must reject a tampered spend_auth_sig instead of splicing it in"
+        );
+    }
+}